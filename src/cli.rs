@@ -1,9 +1,14 @@
+use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use rayon::prelude::*;
 use regex::Regex;
 
+use crate::config;
 use crate::error::{Error, ErrorKind, Result};
+use crate::manifest::{self, Hook, RepoEntry};
+use crate::provider::Registry;
 
 #[derive(Debug, PartialEq)]
 enum Args {
@@ -11,9 +16,17 @@ enum Args {
     Clone {
         base_dir: Option<String>, // -b, --base-dir
         prefer_ssh: bool,         // -s, --ssh
-        url: String,              // <url>
+        urls: Vec<String>,        // <url>...
+        jobs: Option<usize>,      // -j, --jobs
+        backend: Backend,         // --backend cli|gix
         extra_args: Vec<String>,  // -- [extra git args] ...
     },
+    Sync {
+        manifest: Option<String>, // -m, --manifest
+        base_dir: Option<String>, // -b, --base-dir
+        run_hooks: bool,          // --run-hooks
+    },
+    Init, // pacl init
 }
 
 pub fn run() -> Result<()> {
@@ -25,9 +38,11 @@ pub fn run() -> Result<()> {
             Ok(())
         }
         Args::Clone {
-            mut url,
+            urls,
             base_dir,
             prefer_ssh,
+            jobs,
+            backend,
             extra_args,
         } => {
             let base_dir = if let Some(d) = base_dir.or_else(base_dir_from_env) {
@@ -36,39 +51,73 @@ pub fn run() -> Result<()> {
                 default_base_dir()?
             };
 
-            if maybe_github_repository(&url) {
-                url = if prefer_ssh {
-                    format!("{}:{}", "git@github.com", url)
-                } else {
-                    format!("{}/{}", "https://github.com", url)
-                };
-            }
+            let registry = config_registry();
+            let urls: Vec<String> = urls
+                .into_iter()
+                .map(|url| expand_shorthand(&url, &registry, prefer_ssh).unwrap_or(url))
+                .collect();
 
-            do_clone(&url, base_dir, &extra_args)
+            do_clone_all(&urls, &base_dir, &extra_args, jobs, backend)
         }
+        Args::Sync {
+            manifest,
+            base_dir,
+            run_hooks,
+        } => {
+            let base_dir = if let Some(d) = base_dir.or_else(base_dir_from_env) {
+                PathBuf::from(d)
+            } else {
+                default_base_dir()?
+            };
+
+            let manifest_path = if let Some(m) = manifest {
+                PathBuf::from(m)
+            } else {
+                default_manifest_path()?
+            };
+
+            do_sync(&manifest_path, &base_dir, run_hooks)
+        }
+        Args::Init => do_init(),
     }
 }
 
 fn print_usage() {
     println!("usage:");
-    println!("    pacl [options]... <repository url> [-- [extra args passed to git]...]");
+    println!("    pacl [options]... <repository url>... [-- [extra args passed to git]...]");
+    println!("    pacl sync [options]...");
+    println!("    pacl init");
     println!();
     println!("options:");
-    println!("    -h, --help            display this messages and exit");
-    println!("    -b, --base-dir <dir>  base directory to clone");
-    println!("    -s, --ssh             prefer SSH to clone GitHub repository");
+    println!("    -h, --help              display this messages and exit");
+    println!("    -b, --base-dir <dir>    base directory to clone");
+    println!("    -s, --ssh               prefer SSH when expanding a repository shorthand");
+    println!("    -j, --jobs <n>          number of repositories to clone in parallel (default: number of CPUs)");
+    println!("    --backend <cli|gix>     cloning implementation to use (default: cli)");
+    println!("    -m, --manifest <file>   manifest to read for 'sync' (default: ~/.pacl/repos.toml)");
+    println!("    --run-hooks             run post-clone hooks declared in the manifest ('sync' only)");
 }
 
 fn parse_command_line(mut args: impl Iterator<Item = String>) -> Result<Args> {
     let mut base_dir = None;
     let mut prefer_ssh = false;
-    let mut url = None;
+    let mut urls = Vec::new();
+    let mut jobs = None;
+    let mut backend = Backend::default();
     let mut extra_args = None;
+    let mut sync = false;
+    let mut init = false;
+    let mut manifest = None;
+    let mut run_hooks = false;
 
     while let Some(arg) = args.next() {
         match arg.as_str() {
             "-h" | "--help" => return Ok(Args::Help),
 
+            "sync" if !sync && !init && urls.is_empty() => sync = true,
+
+            "init" if !sync && !init && urls.is_empty() => init = true,
+
             "-b" | "--base-dir" => {
                 if let Some(d) = args.next() {
                     base_dir = Some(d);
@@ -77,6 +126,32 @@ fn parse_command_line(mut args: impl Iterator<Item = String>) -> Result<Args> {
                 }
             }
 
+            "-m" | "--manifest" if sync => {
+                if let Some(m) = args.next() {
+                    manifest = Some(m);
+                } else {
+                    return Err(Error::new(ErrorKind::InvalidArg(Some(arg))));
+                }
+            }
+
+            "--run-hooks" if sync => run_hooks = true,
+
+            "-j" | "--jobs" if !sync => {
+                if let Some(n) = args.next().and_then(|n| n.parse().ok()) {
+                    jobs = Some(n);
+                } else {
+                    return Err(Error::new(ErrorKind::InvalidArg(Some(arg))));
+                }
+            }
+
+            "--backend" if !sync => {
+                backend = match args.next().as_deref() {
+                    Some("cli") => Backend::Cli,
+                    Some("gix") => Backend::Gix,
+                    _ => return Err(Error::new(ErrorKind::InvalidArg(Some(arg)))),
+                };
+            }
+
             "-s" | "--ssh" => prefer_ssh = true,
 
             "--" => {
@@ -84,27 +159,35 @@ fn parse_command_line(mut args: impl Iterator<Item = String>) -> Result<Args> {
                 break;
             }
 
-            _ => {
-                if url.is_none() {
-                    url = Some(arg)
-                } else {
-                    return Err(Error::new(ErrorKind::InvalidArg(Some(arg))));
-                }
-            }
+            _ => urls.push(arg),
         }
     }
 
-    if let Some(url) = url {
+    if init {
+        return Ok(Args::Init);
+    }
+
+    if sync {
+        return Ok(Args::Sync {
+            manifest,
+            base_dir,
+            run_hooks,
+        });
+    }
+
+    if urls.is_empty() {
+        Err(Error::new(ErrorKind::MissingRequiredArg(
+            "<url>".to_owned(),
+        )))
+    } else {
         Ok(Args::Clone {
             base_dir,
             prefer_ssh,
-            url,
+            urls,
+            jobs,
+            backend,
             extra_args: extra_args.unwrap_or_default(),
         })
-    } else {
-        Err(Error::new(ErrorKind::MissingRequiredArg(
-            "<url>".to_owned(),
-        )))
     }
 }
 
@@ -127,19 +210,33 @@ fn test_parse_command_line() -> Result<()> {
         Args::Clone {
             base_dir: None,
             prefer_ssh: false,
-            url: "octocat/Spoon-Knife".into(),
+            urls: vec!["octocat/Spoon-Knife".into()],
+            jobs: None,
+            backend: Backend::Cli,
             extra_args: vec![],
         }
     );
 
-    assert!(parse_command_line(["aaa".into(), "bbb".into()].into_iter()).is_err());
+    assert_eq!(
+        parse_command_line(["aaa".into(), "bbb".into()].into_iter())?,
+        Args::Clone {
+            base_dir: None,
+            prefer_ssh: false,
+            urls: vec!["aaa".into(), "bbb".into()],
+            jobs: None,
+            backend: Backend::Cli,
+            extra_args: vec![],
+        }
+    );
 
     assert_eq!(
         parse_command_line(["-b".into(), "nyan".into(), "octocat/Spoon-Knife".into()].into_iter())?,
         Args::Clone {
             base_dir: Some("nyan".into()),
             prefer_ssh: false,
-            url: "octocat/Spoon-Knife".into(),
+            urls: vec!["octocat/Spoon-Knife".into()],
+            jobs: None,
+            backend: Backend::Cli,
             extra_args: vec![],
         }
     );
@@ -148,7 +245,9 @@ fn test_parse_command_line() -> Result<()> {
         Args::Clone {
             base_dir: Some("nyan".into()),
             prefer_ssh: false,
-            url: "octocat/Spoon-Knife".into(),
+            urls: vec!["octocat/Spoon-Knife".into()],
+            jobs: None,
+            backend: Backend::Cli,
             extra_args: vec![],
         }
     );
@@ -164,7 +263,9 @@ fn test_parse_command_line() -> Result<()> {
         Args::Clone {
             base_dir: Some("nyan".into()),
             prefer_ssh: false,
-            url: "octocat/Spoon-Knife".into(),
+            urls: vec!["octocat/Spoon-Knife".into()],
+            jobs: None,
+            backend: Backend::Cli,
             extra_args: vec![],
         }
     );
@@ -176,7 +277,9 @@ fn test_parse_command_line() -> Result<()> {
         Args::Clone {
             base_dir: None,
             prefer_ssh: true,
-            url: "octocat/Spoon-Knife".into(),
+            urls: vec!["octocat/Spoon-Knife".into()],
+            jobs: None,
+            backend: Backend::Cli,
             extra_args: vec![],
         }
     );
@@ -185,17 +288,57 @@ fn test_parse_command_line() -> Result<()> {
         Args::Clone {
             base_dir: None,
             prefer_ssh: true,
-            url: "octocat/Spoon-Knife".into(),
+            urls: vec!["octocat/Spoon-Knife".into()],
+            jobs: None,
+            backend: Backend::Cli,
             extra_args: vec![],
         }
     );
 
+    assert_eq!(
+        parse_command_line(
+            [
+                "-j".into(),
+                "4".into(),
+                "octocat/Spoon-Knife".into(),
+                "octocat/Hello-World".into(),
+            ]
+            .into_iter()
+        )?,
+        Args::Clone {
+            base_dir: None,
+            prefer_ssh: false,
+            urls: vec!["octocat/Spoon-Knife".into(), "octocat/Hello-World".into()],
+            jobs: Some(4),
+            backend: Backend::Cli,
+            extra_args: vec![],
+        }
+    );
+    assert!(parse_command_line(["--jobs".into(), "nope".into()].into_iter()).is_err());
+
+    assert_eq!(
+        parse_command_line(
+            ["--backend".into(), "gix".into(), "octocat/Spoon-Knife".into()].into_iter()
+        )?,
+        Args::Clone {
+            base_dir: None,
+            prefer_ssh: false,
+            urls: vec!["octocat/Spoon-Knife".into()],
+            jobs: None,
+            backend: Backend::Gix,
+            extra_args: vec![],
+        }
+    );
+    assert!(parse_command_line(["--backend".into(), "svn".into()].into_iter()).is_err());
+
     assert_eq!(
         parse_command_line(["octocat/Spoon-Knife".into(), "--".into(),].into_iter())?,
         Args::Clone {
             base_dir: None,
             prefer_ssh: false,
-            url: "octocat/Spoon-Knife".into(),
+            urls: vec!["octocat/Spoon-Knife".into()],
+            jobs: None,
+            backend: Backend::Cli,
             extra_args: vec![],
         }
     );
@@ -213,11 +356,42 @@ fn test_parse_command_line() -> Result<()> {
         Args::Clone {
             base_dir: None,
             prefer_ssh: false,
-            url: "octocat/Spoon-Knife".into(),
+            urls: vec!["octocat/Spoon-Knife".into()],
+            jobs: None,
+            backend: Backend::Cli,
             extra_args: vec!["aaa".into(), "bbb".into()],
         }
     );
 
+    assert_eq!(
+        parse_command_line(["sync".into()].into_iter())?,
+        Args::Sync {
+            manifest: None,
+            base_dir: None,
+            run_hooks: false,
+        }
+    );
+    assert_eq!(
+        parse_command_line(
+            [
+                "sync".into(),
+                "-m".into(),
+                "repos.toml".into(),
+                "-b".into(),
+                "nyan".into(),
+                "--run-hooks".into(),
+            ]
+            .into_iter()
+        )?,
+        Args::Sync {
+            manifest: Some("repos.toml".into()),
+            base_dir: Some("nyan".into()),
+            run_hooks: true,
+        }
+    );
+
+    assert_eq!(parse_command_line(["init".into()].into_iter())?, Args::Init);
+
     Ok(())
 }
 
@@ -226,18 +400,52 @@ fn base_dir_from_env() -> Option<String> {
 }
 
 fn default_base_dir() -> Result<PathBuf> {
+    if let Some(dir) = loaded_config().and_then(|c| c.base_dir) {
+        return Ok(PathBuf::from(dir));
+    }
+
     Ok(home::home_dir()
         .ok_or_else(|| Error::new(ErrorKind::HomeDirectoryNotDetected))?
         .join(".pacl"))
 }
 
-fn do_clone<P, S>(url: &str, base_dir: P, extra_args: &[S]) -> Result<()>
+fn default_manifest_path() -> Result<PathBuf> {
+    if let Some(manifest) = loaded_config().and_then(|c| c.manifest) {
+        return Ok(PathBuf::from(manifest));
+    }
+
+    Ok(home::home_dir()
+        .ok_or_else(|| Error::new(ErrorKind::HomeDirectoryNotDetected))?
+        .join(".pacl")
+        .join("repos.toml"))
+}
+
+/// Which implementation clones repositories: shelling out to a system `git`,
+/// or the pure-Rust `gix` (gitoxide) library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Backend {
+    #[default]
+    Cli,
+    Gix,
+}
+
+fn do_clone<P, S>(url: &str, base_dir: P, extra_args: &[S], backend: Backend) -> Result<()>
 where
     P: AsRef<Path>,
     S: AsRef<std::ffi::OsStr>,
+{
+    match backend {
+        Backend::Cli => do_clone_cli(url, base_dir.as_ref(), extra_args),
+        Backend::Gix => do_clone_gix(url, base_dir.as_ref()),
+    }
+}
+
+fn do_clone_cli<S>(url: &str, base_dir: &Path, extra_args: &[S]) -> Result<()>
+where
+    S: AsRef<std::ffi::OsStr>,
 {
     let path = git_url_to_path(url)?;
-    let path = base_dir.as_ref().join(path);
+    let path = base_dir.join(path);
 
     let status = Command::new("git")
         .arg("clone")
@@ -253,7 +461,341 @@ where
     }
 }
 
-fn maybe_github_repository(url: &str) -> bool {
+fn do_clone_gix(url: &str, base_dir: &Path) -> Result<()> {
+    let path = git_url_to_path(url)?;
+    let path = base_dir.join(path);
+
+    let (mut checkout, _) = gix::prepare_clone(url, &path)
+        .map_err(|e| Error::new(ErrorKind::GixClone(e.to_string())))?
+        .fetch_then_checkout(gix::progress::Discard, &gix::interrupt::IS_INTERRUPTED)
+        .map_err(|e| Error::new(ErrorKind::GixClone(e.to_string())))?;
+    checkout
+        .main_worktree(gix::progress::Discard, &gix::interrupt::IS_INTERRUPTED)
+        .map_err(|e| Error::new(ErrorKind::GixClone(e.to_string())))?;
+
+    Ok(())
+}
+
+fn do_clone_all<S>(
+    urls: &[String],
+    base_dir: &Path,
+    extra_args: &[S],
+    jobs: Option<usize>,
+    backend: Backend,
+) -> Result<()>
+where
+    S: AsRef<std::ffi::OsStr> + Sync,
+{
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs.unwrap_or(0))
+        .build()
+        .map_err(|e| Error::new(ErrorKind::ThreadPoolBuildFailed(e.to_string())))?;
+
+    let results = pool.install(|| {
+        urls.par_iter()
+            .map(|url| {
+                let result = do_clone(url, base_dir, extra_args, backend);
+                match &result {
+                    Ok(()) => println!("cloned '{url}'"),
+                    Err(e) => eprintln!("failed to clone '{url}': {e}"),
+                }
+                result
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let failed = results.iter().filter(|r| r.is_err()).count();
+    let succeeded = results.len() - failed;
+
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(Error::new(ErrorKind::PartialFailure { succeeded, failed }))
+    }
+}
+
+fn do_sync<P: AsRef<Path>>(manifest_path: P, base_dir: &Path, run_hooks: bool) -> Result<()> {
+    let manifest = manifest::load_manifest(manifest_path)?;
+    let registry = config_registry();
+
+    let results: Vec<Result<()>> = manifest
+        .repos
+        .iter()
+        .map(|entry| {
+            let result = sync_repo(entry, base_dir, &manifest.hooks, run_hooks, &registry);
+            if let Err(e) = &result {
+                eprintln!("failed to sync '{}': {e}", entry.url);
+            }
+            result
+        })
+        .collect();
+
+    let failed = results.iter().filter(|r| r.is_err()).count();
+    let succeeded = results.len() - failed;
+
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(Error::new(ErrorKind::PartialFailure { succeeded, failed }))
+    }
+}
+
+#[test]
+fn test_do_sync_continues_past_a_failing_repo() {
+    let dir = std::env::temp_dir().join(format!("pacl-test-do-sync-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let manifest_path = dir.join("repos.toml");
+    fs::write(
+        &manifest_path,
+        r#"
+[[repo]]
+url = ""
+clone = false
+
+[[repo]]
+url = "octocat/Spoon-Knife"
+clone = false
+"#,
+    )
+    .unwrap();
+
+    match do_sync(&manifest_path, &dir, false) {
+        Err(e) => assert_eq!(
+            e.to_string(),
+            ErrorKind::PartialFailure {
+                succeeded: 1,
+                failed: 1
+            }
+            .to_string()
+        ),
+        Ok(()) => panic!("expected a PartialFailure error"),
+    }
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+fn sync_repo(
+    entry: &RepoEntry,
+    default_base_dir: &Path,
+    global_hooks: &[Hook],
+    run_hooks: bool,
+    registry: &Registry,
+) -> Result<()> {
+    let url = expand_shorthand(&entry.url, registry, false).unwrap_or_else(|| entry.url.clone());
+
+    let base_dir = entry
+        .base_dir
+        .as_ref()
+        .map_or_else(|| default_base_dir.to_path_buf(), PathBuf::from);
+    let path = base_dir.join(git_url_to_path(&url)?);
+
+    if !path.exists() {
+        if entry.clone {
+            println!("cloning '{}' into '{}'", url, path.display());
+            do_clone(&url, base_dir, &branch_args(entry), Backend::Cli)?;
+
+            if run_hooks {
+                run_hook_steps(global_hooks, &path)?;
+                run_hook_steps(&entry.hooks, &path)?;
+            }
+        }
+    } else if entry.pull {
+        println!("pulling '{}'", path.display());
+        do_pull(&path)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_sync_repo_bare_shorthand() {
+    // Regression test: `entry.url` used to be passed straight to
+    // `git_url_to_path`, which doesn't understand the bare `owner/repo`
+    // shorthand on its own and errored with `ErrorKind::InvalidArg` before
+    // `entry.clone`/`entry.pull` were even consulted.
+    let dir = std::env::temp_dir().join(format!("pacl-test-sync-{}", std::process::id()));
+    let entry = RepoEntry {
+        url: "octocat/Spoon-Knife".to_owned(),
+        base_dir: None,
+        branch: None,
+        clone: false,
+        pull: false,
+        hooks: vec![],
+    };
+
+    assert!(sync_repo(&entry, &dir, &[], false, &Registry::default()).is_ok());
+}
+
+fn run_hook_steps(hooks: &[Hook], cwd: &Path) -> Result<()> {
+    for hook in hooks {
+        println!("running hook '{}'", hook.name);
+
+        let status = Command::new("sh")
+            .arg("-c")
+            .arg(&hook.run)
+            .current_dir(cwd)
+            .spawn()?
+            .wait()?;
+        match status.code() {
+            Some(0) => {}
+            Some(code) => {
+                return Err(Error::new(ErrorKind::HookFailed {
+                    name: hook.name.clone(),
+                    code,
+                }))
+            }
+            None => {
+                return Err(Error::new(ErrorKind::HookTerminated {
+                    name: hook.name.clone(),
+                }))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_run_hook_steps_runs_in_order() {
+    let dir = std::env::temp_dir().join(format!("pacl-test-hooks-ok-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    let hooks = vec![
+        Hook {
+            name: "touch-a".to_owned(),
+            run: "touch a".to_owned(),
+        },
+        Hook {
+            name: "touch-b-from-a".to_owned(),
+            run: "test -f a && touch b".to_owned(),
+        },
+    ];
+
+    run_hook_steps(&hooks, &dir).unwrap();
+    assert!(dir.join("a").exists());
+    assert!(dir.join("b").exists());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_run_hook_steps_fails_on_non_zero_exit() {
+    let dir = std::env::temp_dir().join(format!("pacl-test-hooks-fail-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    let hooks = vec![Hook {
+        name: "broken".to_owned(),
+        run: "exit 7".to_owned(),
+    }];
+
+    match run_hook_steps(&hooks, &dir) {
+        Err(e) => assert_eq!(e.to_string(), "hook 'broken' failed with status code '7'"),
+        Ok(()) => panic!("expected a HookFailed error"),
+    }
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+fn branch_args(entry: &RepoEntry) -> Vec<String> {
+    match &entry.branch {
+        Some(branch) => vec!["--branch".to_owned(), branch.clone()],
+        None => vec![],
+    }
+}
+
+fn do_pull<P: AsRef<Path>>(path: P) -> Result<()> {
+    let status = Command::new("git")
+        .arg("-C")
+        .arg(path.as_ref())
+        .arg("pull")
+        .spawn()?
+        .wait()?;
+    match status.code() {
+        Some(0) => Ok(()),
+        Some(code) => Err(Error::new(ErrorKind::GitReturnedNonZero(code))),
+        None => Err(Error::new(ErrorKind::GitTerminated)),
+    }
+}
+
+fn config_path() -> Option<PathBuf> {
+    home::home_dir().map(|h| h.join(".pacl").join("config.toml"))
+}
+
+fn loaded_config() -> Option<config::Config> {
+    config_path().and_then(|p| config::load_config(p).ok())
+}
+
+fn config_registry() -> Registry {
+    loaded_config().map(|c| c.registry()).unwrap_or_default()
+}
+
+fn do_init() -> Result<()> {
+    let path = config_path().ok_or_else(|| Error::new(ErrorKind::HomeDirectoryNotDetected))?;
+    init_config_at(&path)
+}
+
+fn init_config_at(path: &Path) -> Result<()> {
+    if path.exists() {
+        println!(
+            "configuration already exists at '{}' — not overwriting",
+            path.display()
+        );
+        return Ok(());
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, include_str!("default.toml"))?;
+    println!("created default configuration at '{}'", path.display());
+
+    Ok(())
+}
+
+#[test]
+fn test_init_config_at_does_not_overwrite() {
+    let dir = std::env::temp_dir().join(format!("pacl-test-init-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("config.toml");
+
+    init_config_at(&path).unwrap();
+    let created = fs::read_to_string(&path).unwrap();
+    assert_eq!(created, include_str!("default.toml"));
+
+    fs::write(&path, "default_provider = \"work\"\n").unwrap();
+    init_config_at(&path).unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), "default_provider = \"work\"\n");
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+/// Expand a provider shorthand into a full URL, e.g. `gh:octocat/Spoon-Knife`
+/// into `https://github.com/octocat/Spoon-Knife`, or a bare `owner/repo`
+/// through the registry's default provider. Returns `None` if `url` isn't a
+/// shorthand form, in which case it should be used as-is.
+fn expand_shorthand(url: &str, registry: &Registry, prefer_ssh: bool) -> Option<String> {
+    if let Some((alias, path)) = url.split_once(':') {
+        if is_alias_name(alias) {
+            if let Some(provider) = registry.get(alias) {
+                return Some(provider.expand(path, prefer_ssh));
+            }
+        }
+    }
+
+    if maybe_bare_repository(url) {
+        if let Some(provider) = registry.default_provider() {
+            return Some(provider.expand(url, prefer_ssh));
+        }
+    }
+
+    None
+}
+
+fn is_alias_name(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+fn maybe_bare_repository(url: &str) -> bool {
     match url.split_once('/') {
         Some((owner, repository)) => {
             let f1 = |c: char| c.is_ascii_alphanumeric() || c == '-';
@@ -265,17 +807,45 @@ fn maybe_github_repository(url: &str) -> bool {
 }
 
 #[test]
-fn test_maybe_github_repository() {
-    assert!(maybe_github_repository("octocat/Spoon-Knife"));
-    assert!(maybe_github_repository("octocat/octocat.github.io"));
-    assert!(maybe_github_repository("Tosainu/foo_bar"));
-    assert!(maybe_github_repository("Tosainu-/foo_bar"));
-
-    assert!(!maybe_github_repository(""));
-    assert!(!maybe_github_repository("myon.info"));
-    assert!(!maybe_github_repository("myon.info/foo_bar"));
-    assert!(!maybe_github_repository("Tosainu=/foo_bar"));
-    assert!(!maybe_github_repository("Tosainu_/foo_bar"));
+fn test_maybe_bare_repository() {
+    assert!(maybe_bare_repository("octocat/Spoon-Knife"));
+    assert!(maybe_bare_repository("octocat/octocat.github.io"));
+    assert!(maybe_bare_repository("Tosainu/foo_bar"));
+    assert!(maybe_bare_repository("Tosainu-/foo_bar"));
+
+    assert!(!maybe_bare_repository(""));
+    assert!(!maybe_bare_repository("myon.info"));
+    assert!(!maybe_bare_repository("myon.info/foo_bar"));
+    assert!(!maybe_bare_repository("Tosainu=/foo_bar"));
+    assert!(!maybe_bare_repository("Tosainu_/foo_bar"));
+}
+
+#[test]
+fn test_expand_shorthand() {
+    let registry = Registry::default();
+
+    assert_eq!(
+        expand_shorthand("octocat/Spoon-Knife", &registry, false),
+        Some("https://github.com/octocat/Spoon-Knife".to_owned())
+    );
+    assert_eq!(
+        expand_shorthand("octocat/Spoon-Knife", &registry, true),
+        Some("git@github.com:octocat/Spoon-Knife".to_owned())
+    );
+    assert_eq!(
+        expand_shorthand("gl:octocat/Spoon-Knife", &registry, false),
+        Some("https://gitlab.com/octocat/Spoon-Knife".to_owned())
+    );
+    assert_eq!(
+        expand_shorthand("gl:octocat/Spoon-Knife", &registry, true),
+        Some("git@gitlab.com:octocat/Spoon-Knife".to_owned())
+    );
+
+    assert_eq!(expand_shorthand("myon.info", &registry, false), None);
+    assert_eq!(
+        expand_shorthand("git@host:foo/bar/baz.git", &registry, false),
+        None
+    );
 }
 
 fn git_url_to_path(url: &str) -> Result<String> {
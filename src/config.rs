@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::error::{Error, ErrorKind, Result};
+use crate::provider::{Provider, Registry};
+
+/// User config, e.g. `~/.pacl/config.toml`.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Config {
+    pub base_dir: Option<String>,
+    pub manifest: Option<String>,
+    #[serde(default = "default_provider_name")]
+    pub default_provider: String,
+    #[serde(default)]
+    pub provider: HashMap<String, ProviderConfig>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct ProviderConfig {
+    pub https_base: String,
+    pub ssh_host: String,
+}
+
+fn default_provider_name() -> String {
+    "gh".to_owned()
+}
+
+impl Config {
+    pub fn registry(&self) -> Registry {
+        let mut registry = Registry::default();
+        registry.set_default_provider(&self.default_provider);
+        for (name, p) in &self.provider {
+            registry.insert(
+                name.clone(),
+                Provider::new(p.https_base.clone(), p.ssh_host.clone()),
+            );
+        }
+        registry
+    }
+}
+
+pub fn load_config(path: impl AsRef<Path>) -> Result<Config> {
+    let content = fs::read_to_string(path)?;
+    toml::from_str(&content).map_err(|e| Error::new(ErrorKind::ConfigParse(e.to_string())))
+}
+
+#[test]
+fn test_load_config_with_alias() {
+    let dir = std::env::temp_dir().join(format!("pacl-test-config-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("config.toml");
+    fs::write(
+        &path,
+        r#"
+default_provider = "work"
+
+[provider.work]
+https_base = "https://git.example.com"
+ssh_host = "git.example.com"
+"#,
+    )
+    .unwrap();
+
+    let config = load_config(&path).unwrap();
+    let registry = config.registry();
+    assert_eq!(
+        registry.default_provider(),
+        Some(&Provider::new("https://git.example.com", "git.example.com"))
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -21,6 +21,13 @@ pub enum ErrorKind {
     Io(std::io::Error),
     InvalidArg(Option<String>),
     MissingRequiredArg(String),
+    ManifestParse(String),
+    ConfigParse(String),
+    PartialFailure { succeeded: usize, failed: usize },
+    GixClone(String),
+    HookFailed { name: String, code: i32 },
+    HookTerminated { name: String },
+    ThreadPoolBuildFailed(String),
 }
 
 impl fmt::Display for Error {
@@ -46,6 +53,21 @@ impl fmt::Display for ErrorKind {
                 Ok(())
             }
             ErrorKind::MissingRequiredArg(arg) => write!(f, "missing required argument '{arg}'"),
+            ErrorKind::ManifestParse(e) => write!(f, "failed to parse manifest: {e}"),
+            ErrorKind::ConfigParse(e) => write!(f, "failed to parse config: {e}"),
+            ErrorKind::PartialFailure { succeeded, failed } => write!(
+                f,
+                "{failed} of {} clones failed",
+                succeeded + failed
+            ),
+            ErrorKind::GixClone(e) => write!(f, "gix: {e}"),
+            ErrorKind::HookFailed { name, code } => {
+                write!(f, "hook '{name}' failed with status code '{code}'")
+            }
+            ErrorKind::HookTerminated { name } => write!(f, "hook '{name}' terminated by signal"),
+            ErrorKind::ThreadPoolBuildFailed(e) => {
+                write!(f, "failed to build thread pool: {e}")
+            }
         }
     }
 }
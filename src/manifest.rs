@@ -0,0 +1,105 @@
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::error::{Error, ErrorKind, Result};
+
+/// A declarative multi-repo manifest, e.g. `~/.pacl/repos.toml`.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Manifest {
+    #[serde(default, rename = "repo")]
+    pub repos: Vec<RepoEntry>,
+    /// Hooks that run after every successful clone, in addition to any
+    /// repo-specific ones.
+    #[serde(default)]
+    pub hooks: Vec<Hook>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct RepoEntry {
+    pub url: String,
+    pub base_dir: Option<String>,
+    pub branch: Option<String>,
+    #[serde(default = "default_true")]
+    pub clone: bool,
+    #[serde(default)]
+    pub pull: bool,
+    #[serde(default)]
+    pub hooks: Vec<Hook>,
+}
+
+/// A post-clone setup step, e.g. installing dependencies or initializing
+/// submodules, run in the freshly cloned directory.
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+pub struct Hook {
+    pub name: String,
+    pub run: String,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+pub fn load_manifest(path: impl AsRef<Path>) -> Result<Manifest> {
+    let content = fs::read_to_string(path)?;
+    toml::from_str(&content).map_err(|e| Error::new(ErrorKind::ManifestParse(e.to_string())))
+}
+
+#[test]
+fn test_load_manifest() {
+    let dir = std::env::temp_dir().join(format!("pacl-test-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("repos.toml");
+    fs::write(
+        &path,
+        r#"
+[[repo]]
+url = "octocat/Spoon-Knife"
+clone = true
+
+[[repo.hooks]]
+name = "install"
+run = "npm install"
+
+[[repo]]
+url = "user@host:hoge/fuga"
+base_dir = "/tmp/fuga"
+branch = "main"
+pull = true
+clone = false
+"#,
+    )
+    .unwrap();
+
+    let manifest = load_manifest(&path).unwrap();
+    assert_eq!(
+        manifest,
+        Manifest {
+            repos: vec![
+                RepoEntry {
+                    url: "octocat/Spoon-Knife".to_owned(),
+                    base_dir: None,
+                    branch: None,
+                    clone: true,
+                    pull: false,
+                    hooks: vec![Hook {
+                        name: "install".to_owned(),
+                        run: "npm install".to_owned(),
+                    }],
+                },
+                RepoEntry {
+                    url: "user@host:hoge/fuga".to_owned(),
+                    base_dir: Some("/tmp/fuga".to_owned()),
+                    branch: Some("main".to_owned()),
+                    clone: false,
+                    pull: true,
+                    hooks: vec![],
+                },
+            ],
+            hooks: vec![],
+        }
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+
+/// A git hosting provider: an HTTPS base URL plus the host used for the
+/// `git@<host>:...` SSH form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Provider {
+    pub https_base: String,
+    pub ssh_host: String,
+}
+
+impl Provider {
+    pub fn new(https_base: impl Into<String>, ssh_host: impl Into<String>) -> Self {
+        Self {
+            https_base: https_base.into(),
+            ssh_host: ssh_host.into(),
+        }
+    }
+
+    pub fn expand(&self, path: &str, prefer_ssh: bool) -> String {
+        if prefer_ssh {
+            format!("git@{}:{}", self.ssh_host, path)
+        } else {
+            format!("{}/{}", self.https_base, path)
+        }
+    }
+}
+
+/// Registry of provider aliases (`gh`, `gl`, ...) resolved when expanding
+/// shorthand repository references, plus whichever one is used for the bare
+/// `owner/repo` form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Registry {
+    providers: HashMap<String, Provider>,
+    default_provider: String,
+}
+
+impl Registry {
+    pub fn with_default(default_provider: impl Into<String>) -> Self {
+        Self {
+            providers: HashMap::new(),
+            default_provider: default_provider.into(),
+        }
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, provider: Provider) -> &mut Self {
+        self.providers.insert(name.into(), provider);
+        self
+    }
+
+    pub fn set_default_provider(&mut self, name: impl Into<String>) -> &mut Self {
+        self.default_provider = name.into();
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Provider> {
+        self.providers.get(name)
+    }
+
+    pub fn default_provider(&self) -> Option<&Provider> {
+        self.providers.get(&self.default_provider)
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        let mut registry = Self::with_default("gh");
+        registry
+            .insert("gh", Provider::new("https://github.com", "github.com"))
+            .insert("gl", Provider::new("https://gitlab.com", "gitlab.com"));
+        registry
+    }
+}
+
+#[test]
+fn test_registry_defaults() {
+    let registry = Registry::default();
+    assert_eq!(
+        registry.get("gh"),
+        Some(&Provider::new("https://github.com", "github.com"))
+    );
+    assert_eq!(
+        registry.get("gl"),
+        Some(&Provider::new("https://gitlab.com", "gitlab.com"))
+    );
+    assert_eq!(registry.get("nope"), None);
+    assert_eq!(registry.default_provider(), registry.get("gh"));
+}
+
+#[test]
+fn test_registry_custom_alias_and_default() {
+    let mut registry = Registry::default();
+    registry.insert("work", Provider::new("https://git.example.com", "git.example.com"));
+    registry.set_default_provider("work");
+
+    assert_eq!(
+        registry.get("work"),
+        Some(&Provider::new("https://git.example.com", "git.example.com"))
+    );
+    assert_eq!(registry.default_provider(), registry.get("work"));
+}
+
+#[test]
+fn test_provider_expand() {
+    let gh = Provider::new("https://github.com", "github.com");
+    assert_eq!(
+        gh.expand("octocat/Spoon-Knife", false),
+        "https://github.com/octocat/Spoon-Knife"
+    );
+    assert_eq!(
+        gh.expand("octocat/Spoon-Knife", true),
+        "git@github.com:octocat/Spoon-Knife"
+    );
+}